@@ -1,18 +1,46 @@
 use std::error::Error;
+use std::time::Duration;
 
-use astrorust::data_utils::data_utils::{download_tai_utc_data, file_exists, get_bsp_file};
+use astrorust::data_utils::data_utils::{
+    default_tai_utc_sources, file_exists, get_bsp_file, get_tai_utc_data, DEFAULT_MAX_AGE_S,
+};
 
 fn main() -> Result<(), Box<dyn Error>> {
-    // Download TAI-UTC data needed for UTC conversions (you will need internet connection)
-    download_tai_utc_data()?;
+    // Download TAI-UTC data needed for UTC conversions if it's missing or
+    // stale, trying the USNO source and then the IETF mirrors in order
+    // (you will need an internet connection unless a cached copy is fresh).
+    let freshness = get_tai_utc_data(
+        &default_tai_utc_sources(),
+        false,
+        Duration::from_secs(DEFAULT_MAX_AGE_S),
+    )?;
+    println!("TAI-UTC data freshness: {:?}", freshness);
 
     // Check if the file has been downloaded and exists
     let filename = "data/tai-utc.dat";
     let file_exists = file_exists(filename);
     println!("File '{}' exists: {}.", filename, file_exists);
 
-    // Downloads a .bsp file if it does not exist or if an update is requested
-    get_bsp_file("de405.bsp", false, 5.0)?;
+    // Downloads a .bsp file if it does not exist or if an update is requested,
+    // printing progress as it streams in
+    get_bsp_file(
+        "de405.bsp",
+        false,
+        5.0,
+        Some(Box::new(|progress| {
+            println!(
+                "{:.1}% ({:.1} KB/s)",
+                progress.percentage_done.unwrap_or(0.0),
+                progress.last_throughput / 1024.0
+            );
+            true
+        })),
+        None,
+        Some(Box::new(|path| {
+            println!("Ready to parse DAF segments from {}", path.display());
+        })),
+        None,
+    )?;
 
     Ok(())
 }
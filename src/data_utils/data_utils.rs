@@ -2,11 +2,11 @@ use std::fs::File;
 use std::path::Path;
 
 use reqwest;
+use sha2::{Digest, Sha256};
 use std::error::Error;
-use std::io::{self, Write};
-use std::sync::Arc;
-use std::thread;
-use std::time::{Duration, Instant};
+use std::io::{self, Read, Write};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 /// Downloads the TAI-UTC data file from the U.S. Naval Observatory website.
 ///
@@ -21,16 +21,37 @@ use std::time::{Duration, Instant};
 /// - If the HTTP request to retrieve the data fails.
 /// - If the HTTP response body is invalid or cannot be read as text.
 /// - If the local file "data/tai-utc.dat" cannot be created or written to.
+/// - If the downloaded body contains no parseable leap-second rows (e.g. a
+///   captive-portal page saved in place of the real file), so a corrupt
+///   download can't silently break [`tai_minus_utc`].
 ///
 /// # Returns
 ///
 /// If the data is successfully downloaded and saved, it returns `Ok(())`.
 pub fn download_tai_utc_data() -> Result<(), Box<dyn Error>> {
-    // Get response from U.S. Naval Observatory's website
-    let resp = reqwest::blocking::get("https://maia.usno.navy.mil/ser7/tai-utc.dat")?;
+    download_tai_utc_data_from(USNO_TAI_UTC_URL)
+}
+
+/// Downloads a USNO-style `tai-utc.dat` file from `url` and saves it to
+/// `data/tai-utc.dat`.
+///
+/// # Errors
+///
+/// Returns an error if the HTTP request fails, the local file cannot be
+/// created or written to, or the downloaded body contains no parseable
+/// leap-second rows (e.g. a captive-portal page saved in place of the real
+/// file).
+fn download_tai_utc_data_from(url: &str) -> Result<(), Box<dyn Error>> {
+    // Get response from the requested source
+    let resp = reqwest::blocking::get(url)?;
 
     // Get the response text
     let body = resp.text()?;
+
+    if !looks_like_tai_utc_dat(&body) {
+        return Err("downloaded tai-utc.dat contains no parseable leap-second rows".into());
+    }
+
     // Create file in the location specified
     let mut out = File::create("data/tai-utc.dat")?;
     // Copies the entire contents of a reader into a writer.
@@ -39,6 +60,71 @@ pub fn download_tai_utc_data() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+/// Reports whether `body` looks like a genuine `tai-utc.dat`: at least one
+/// line parses as a leap-second row.
+fn looks_like_tai_utc_dat(body: &str) -> bool {
+    body.lines().any(|line| parse_tai_utc_line(line).is_some())
+}
+
+/// Reports whether `body` looks like a genuine `leap-seconds.list`: at
+/// least one line parses as a leap-second row.
+fn looks_like_leap_seconds_list(body: &str) -> bool {
+    body.lines().any(|line| parse_leap_seconds_list_line(line).is_some())
+}
+
+/// The primary USNO source for `tai-utc.dat`.
+pub const USNO_TAI_UTC_URL: &str = "https://maia.usno.navy.mil/ser7/tai-utc.dat";
+
+/// Mirrors of the IETF `leap-seconds.list` format, tried after the USNO
+/// source so a single outage doesn't break time-scale conversions.
+pub const IETF_LEAP_SECONDS_LIST_URLS: &[&str] = &[
+    "https://www.ietf.org/timezones/data/leap-seconds.list",
+    "https://raw.githubusercontent.com/eggert/tz/main/leap-seconds.list",
+];
+
+/// The default ordered list of sources tried by [`get_tai_utc_data`]: the
+/// USNO `tai-utc.dat` first, then the IETF `leap-seconds.list` mirrors.
+pub fn default_tai_utc_sources() -> Vec<&'static str> {
+    std::iter::once(USNO_TAI_UTC_URL)
+        .chain(IETF_LEAP_SECONDS_LIST_URLS.iter().copied())
+        .collect()
+}
+
+/// Downloads a `leap-seconds.list` file from `url` and saves it to
+/// `data/leap-seconds.list`.
+///
+/// # Errors
+///
+/// Returns an error if the HTTP request fails, the local file cannot be
+/// created or written to, or the downloaded body contains no parseable
+/// leap-second rows (e.g. a captive-portal page saved in place of the real
+/// file).
+pub fn download_leap_seconds_list(url: &str) -> Result<(), Box<dyn Error>> {
+    let resp = reqwest::blocking::get(url)?;
+    let body = resp.text()?;
+
+    if !looks_like_leap_seconds_list(&body) {
+        return Err("downloaded leap-seconds.list contains no parseable leap-second rows".into());
+    }
+
+    let mut out = File::create("data/leap-seconds.list")?;
+    io::copy(&mut body.as_bytes(), &mut out)?;
+
+    Ok(())
+}
+
+/// Downloads from whichever source format `url` points to: a
+/// `leap-seconds.list` URL is saved to `data/leap-seconds.list`, anything
+/// else is treated as a USNO `tai-utc.dat` source and fetched from `url`
+/// itself, not just the default USNO mirror.
+fn download_leap_second_source(url: &str) -> Result<(), Box<dyn Error>> {
+    if url.ends_with("leap-seconds.list") {
+        download_leap_seconds_list(url)
+    } else {
+        download_tai_utc_data_from(url)
+    }
+}
+
 /// Checks whether a file exists.
 ///
 /// ## Arguments
@@ -50,34 +136,477 @@ pub fn file_exists(filename: &str) -> bool {
     Path::new(&filename).exists()
 }
 
-/// Checks if the TAI-UTC data file exists and downloads it if necessary.
+/// Default maximum age before cached leap-second data is considered stale,
+/// independent of any expiry the data itself declares.
+pub const DEFAULT_MAX_AGE_S: u64 = 30 * 24 * 60 * 60;
+
+/// Reports how the data returned by [`get_tai_utc_data`] was obtained.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataFreshness {
+    /// A cached copy was present and not yet stale; nothing was downloaded.
+    Fresh,
+    /// The cached copy was missing or stale, and a fresh copy was
+    /// downloaded successfully.
+    Refreshed,
+    /// Every source failed to download; the stale cached copy was used as an
+    /// offline fallback.
+    StaleFallback,
+}
+
+/// Returns the path of whichever leap-second cache file is present. If both
+/// exist, prefers whichever was downloaded more recently (by mtime), since
+/// the other is left over from a source that has since been superseded.
+fn cached_path() -> Option<&'static str> {
+    let tai_utc = file_exists("data/tai-utc.dat").then_some("data/tai-utc.dat");
+    let ietf = file_exists("data/leap-seconds.list").then_some("data/leap-seconds.list");
+
+    match (tai_utc, ietf) {
+        (Some(tai_utc), Some(ietf)) => {
+            if file_mtime(ietf) > file_mtime(tai_utc) {
+                Some(ietf)
+            } else {
+                Some(tai_utc)
+            }
+        }
+        (Some(path), None) | (None, Some(path)) => Some(path),
+        (None, None) => None,
+    }
+}
+
+/// Returns the last-modified time of the file at `path`, or `UNIX_EPOCH` if
+/// it cannot be determined.
+fn file_mtime(path: &str) -> SystemTime {
+    std::fs::metadata(path)
+        .and_then(|m| m.modified())
+        .unwrap_or(UNIX_EPOCH)
+}
+
+/// Reads the `#@` expiry line from an IETF `leap-seconds.list` file and
+/// returns it as Unix seconds, or `None` if no expiry line is present.
+fn read_ietf_expiry(path: &str) -> Option<i64> {
+    let contents = std::fs::read_to_string(path).ok()?;
+    contents.lines().find_map(|line| {
+        let ntp_seconds: i64 = line.trim().strip_prefix("#@")?.trim().parse().ok()?;
+        Some(ntp_seconds - NTP_UNIX_EPOCH_OFFSET_S)
+    })
+}
+
+/// Reports whether the cached file at `path` is stale: past its declared
+/// expiry (for `leap-seconds.list`) or older than `max_age` (for
+/// `tai-utc.dat`, judged by file mtime).
+fn is_stale(path: &str, max_age: Duration) -> bool {
+    if path.ends_with("leap-seconds.list") {
+        let Some(expiry_unix) = read_ietf_expiry(path) else {
+            return true;
+        };
+        let now_unix = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs() as i64)
+            .unwrap_or(0);
+        now_unix >= expiry_unix
+    } else {
+        let Ok(modified) = std::fs::metadata(path).and_then(|m| m.modified()) else {
+            return true;
+        };
+        SystemTime::now()
+            .duration_since(modified)
+            .map(|age| age > max_age)
+            .unwrap_or(false)
+    }
+}
+
+/// Checks if leap-second data is cached locally and downloads it if stale
+/// or missing, trying each of `sources` in order until one succeeds.
+///
+/// `sources` is an ordered list of URLs, each either a USNO-style
+/// `tai-utc.dat` or an IETF-style `leap-seconds.list`; see
+/// [`default_tai_utc_sources`] for the list used by callers that don't need
+/// to customize it. Trying multiple sources means a single server outage
+/// doesn't break time-scale conversions.
 ///
 /// # Arguments
 ///
-/// * `update_file` - If `true`, force an update even if the file already exists.
+/// * `sources` - Ordered URLs to try, most preferred first.
+/// * `update_file` - If `true`, force a re-download even if the cached copy is fresh.
+/// * `max_age` - How old a `tai-utc.dat` cache may be before it's considered
+///   stale; see [`DEFAULT_MAX_AGE_S`]. Ignored for `leap-seconds.list`, which
+///   carries its own expiry line.
 ///
 /// # Errors
 ///
-/// This function returns a `Result<(), Box<dyn Error>>`:
-///
-/// - If the file already exists but `update_file` is `true`, and the update fails.
-/// - If any other error occurs while checking the file or downloading it.
+/// Returns an error if no cached copy exists and every source in `sources`
+/// fails to download.
 ///
 /// # Returns
 ///
-/// If the file is already up-to-date or successfully updated, it returns `Ok(())`.
-pub fn get_tai_utc_data(update_file: bool) -> Result<(), Box<dyn Error>> {
-    // First check if the file already exists
-    let file_exists = file_exists("data/tai-utc.dat");
+/// A [`DataFreshness`] reporting whether the returned data was already
+/// fresh, freshly downloaded, or a stale offline fallback.
+pub fn get_tai_utc_data(
+    sources: &[&str],
+    update_file: bool,
+    max_age: Duration,
+) -> Result<DataFreshness, Box<dyn Error>> {
+    let was_stale = match cached_path() {
+        Some(path) => is_stale(path, max_age),
+        None => true,
+    };
 
-    // If the file does not exist or if update is requested, download it
-    if !file_exists || update_file {
-        download_tai_utc_data()?;
+    if !update_file && !was_stale {
+        return Ok(DataFreshness::Fresh);
     }
 
-    Ok(())
+    let mut last_err: Option<Box<dyn Error>> = None;
+    for source in sources {
+        match download_leap_second_source(source) {
+            Ok(()) => return Ok(DataFreshness::Refreshed),
+            Err(err) => {
+                println!("Failed to download leap-second data from {}: {}", source, err);
+                last_err = Some(err);
+            }
+        }
+    }
+
+    if cached_path().is_none() {
+        return Err(last_err.unwrap_or_else(|| "no leap-second sources configured".into()));
+    }
+
+    // `update_file` can force this refresh attempt even though the cache
+    // wasn't actually stale; if every source then fails, the cache in use
+    // is still the fresh one from before the attempt, not a stale fallback.
+    if was_stale {
+        Ok(DataFreshness::StaleFallback)
+    } else {
+        Ok(DataFreshness::Fresh)
+    }
 }
 
+/// A single row parsed from a leap-second data file.
+///
+/// Each row gives the TAI-UTC offset, in seconds, that applies from
+/// `effective_jd` onwards: `base_offset + (mjd - mjd_epoch) * drift_per_day`,
+/// where `mjd` is the Modified Julian Date being queried.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LeapSecondRow {
+    pub effective_jd: f64,
+    pub base_offset: f64,
+    pub mjd_epoch: f64,
+    pub drift_per_day: f64,
+}
+
+impl LeapSecondRow {
+    fn tai_minus_utc(&self, jd: f64) -> f64 {
+        let mjd = jd - 2_400_000.5;
+        self.base_offset + (mjd - self.mjd_epoch) * self.drift_per_day
+    }
+}
+
+/// A sorted table of leap-second rows parsed from `data/tai-utc.dat` or
+/// `data/leap-seconds.list`.
+///
+/// Use [`LeapSecondTable::tai_minus_utc`] to look up the TAI-UTC offset in
+/// effect at a given Julian date. The table is cached for reuse across
+/// conversions and re-parsed whenever the cached source file changes; see
+/// [`leap_second_table`].
+#[derive(Debug, Clone)]
+pub struct LeapSecondTable {
+    rows: Vec<LeapSecondRow>,
+}
+
+impl LeapSecondTable {
+    /// Parses a leap-second file into a sorted `LeapSecondTable`. A path
+    /// ending in `leap-seconds.list` is read with [`Self::parse_ietf`];
+    /// any other path is read as a USNO `tai-utc.dat`, whose lines have the
+    /// form:
+    ///
+    /// ```text
+    ///  1972 JAN  1 =JD 2441317.5  TAI-UTC=  10.0       S + (MJD - 41317.) X 0.0      S
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read, or if it contains no
+    /// parseable rows.
+    pub fn parse(path: &str) -> Result<Self, Box<dyn Error>> {
+        let contents = std::fs::read_to_string(path)?;
+        if path.ends_with("leap-seconds.list") {
+            Self::parse_ietf(&contents)
+        } else {
+            Self::parse_usno(&contents, path)
+        }
+    }
+
+    /// Parses the contents of a USNO `tai-utc.dat` file already held in memory.
+    ///
+    /// `source` is only used to produce a descriptive error message.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `contents` contains no parseable rows.
+    pub fn parse_usno(contents: &str, source: &str) -> Result<Self, Box<dyn Error>> {
+        let mut rows: Vec<LeapSecondRow> = contents
+            .lines()
+            .filter_map(parse_tai_utc_line)
+            .collect();
+
+        if rows.is_empty() {
+            return Err(format!("no parseable leap-second rows found in {}", source).into());
+        }
+
+        // `total_cmp` (rather than `partial_cmp().unwrap()`) tolerates a NaN
+        // `effective_jd` from a corrupted row instead of panicking; such a
+        // row sorts to one end and is merely useless, not process-ending.
+        rows.sort_by(|a, b| a.effective_jd.total_cmp(&b.effective_jd));
+
+        Ok(Self { rows })
+    }
+
+    /// Parses the contents of an IETF/`eggert/tz` `leap-seconds.list` file
+    /// already held in memory.
+    ///
+    /// Each data row has the form `<ntp_seconds> <tai_minus_utc_seconds>`,
+    /// where `ntp_seconds` is an NTP timestamp (seconds since 1900-01-01)
+    /// converted here to a Julian date; comment lines starting with `#` are
+    /// ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `contents` contains no parseable rows.
+    pub fn parse_ietf(contents: &str) -> Result<Self, Box<dyn Error>> {
+        let mut rows: Vec<LeapSecondRow> = contents
+            .lines()
+            .filter_map(parse_leap_seconds_list_line)
+            .collect();
+
+        if rows.is_empty() {
+            return Err("no parseable leap-second rows found in leap-seconds.list".into());
+        }
+
+        rows.sort_by(|a, b| a.effective_jd.total_cmp(&b.effective_jd));
+
+        Ok(Self { rows })
+    }
+
+    /// Looks up the TAI-UTC offset, in seconds, in effect at the given Julian date.
+    ///
+    /// If `jd` predates the first entry in the table, the offset of that
+    /// first entry is still used as a best-effort fallback, since TAI-UTC
+    /// was undefined (no leap seconds existed) before it.
+    pub fn tai_minus_utc(&self, jd: f64) -> f64 {
+        let row = self
+            .rows
+            .iter()
+            .rev()
+            .find(|row| row.effective_jd <= jd)
+            .unwrap_or(&self.rows[0]);
+
+        row.tai_minus_utc(jd)
+    }
+}
+
+/// Parses a single `tai-utc.dat` line of the form
+/// `<date> =JD <jd>  TAI-UTC= <offset> S + (MJD - <epoch>) X <drift> S`.
+///
+/// Returns `None` if the line does not match this shape (e.g. blank lines).
+fn parse_tai_utc_line(line: &str) -> Option<LeapSecondRow> {
+    let (_, rest) = line.split_once("=JD")?;
+    let (jd_str, rest) = rest.split_once("TAI-UTC=")?;
+    let effective_jd: f64 = jd_str.trim().parse().ok()?;
+
+    let (offset_str, rest) = rest.split_once('S')?;
+    let base_offset: f64 = offset_str.trim().parse().ok()?;
+
+    let (_, rest) = rest.split_once("MJD -")?;
+    let (epoch_str, rest) = rest.split_once(')')?;
+    let mjd_epoch: f64 = epoch_str.trim().parse().ok()?;
+
+    let (_, drift_str) = rest.split_once('X')?;
+    let drift_per_day: f64 = drift_str.trim().trim_end_matches('S').trim().parse().ok()?;
+
+    Some(LeapSecondRow {
+        effective_jd,
+        base_offset,
+        mjd_epoch,
+        drift_per_day,
+    })
+}
+
+/// Seconds between the NTP epoch (1900-01-01) and the Unix epoch (1970-01-01).
+const NTP_UNIX_EPOCH_OFFSET_S: i64 = 2_208_988_800;
+
+/// Parses a single `leap-seconds.list` data line of the form
+/// `<ntp_seconds> <tai_minus_utc_seconds>`.
+///
+/// Returns `None` for comment lines (starting with `#`), blank lines, or
+/// lines that don't match this shape.
+fn parse_leap_seconds_list_line(line: &str) -> Option<LeapSecondRow> {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('#') {
+        return None;
+    }
+
+    let mut fields = line.split_whitespace();
+    let ntp_seconds: i64 = fields.next()?.parse().ok()?;
+    let base_offset: f64 = fields.next()?.parse().ok()?;
+
+    Some(LeapSecondRow {
+        effective_jd: ntp_seconds_to_julian_days(ntp_seconds),
+        base_offset,
+        mjd_epoch: 0.0,
+        drift_per_day: 0.0,
+    })
+}
+
+/// Converts an NTP timestamp (seconds since 1900-01-01) to a Julian date.
+fn ntp_seconds_to_julian_days(ntp_seconds: i64) -> f64 {
+    let unix_seconds = ntp_seconds - NTP_UNIX_EPOCH_OFFSET_S;
+    unix_seconds as f64 / 86400.0 + 2_440_587.5
+}
+
+/// The currently cached table together with the path and mtime it was
+/// parsed from, so a later call can tell whether the on-disk file has since
+/// been replaced (e.g. by a successful refresh from [`get_tai_utc_data`]).
+struct CachedTable {
+    path: &'static str,
+    modified: SystemTime,
+    table: Arc<LeapSecondTable>,
+}
+
+static LEAP_SECOND_TABLE: Mutex<Option<CachedTable>> = Mutex::new(None);
+
+/// Returns the process-wide cached [`LeapSecondTable`], parsing the cached
+/// leap-second file on first use. Re-parses it whenever [`cached_path`] or
+/// its mtime has changed since the last parse, so a successful refresh from
+/// [`get_tai_utc_data`] is picked up instead of the process using a stale
+/// table for the rest of its lifetime.
+///
+/// # Errors
+///
+/// Returns an error if no cached file is present, or if parsing it fails.
+pub fn leap_second_table() -> Result<Arc<LeapSecondTable>, Box<dyn Error>> {
+    let path = cached_path().ok_or("no cached leap-second data file found")?;
+    let modified = file_mtime(path);
+
+    let mut cached = LEAP_SECOND_TABLE.lock().unwrap();
+    if let Some(entry) = cached.as_ref() {
+        if entry.path == path && entry.modified == modified {
+            return Ok(entry.table.clone());
+        }
+    }
+
+    let table = Arc::new(LeapSecondTable::parse(path)?);
+    *cached = Some(CachedTable {
+        path,
+        modified,
+        table: table.clone(),
+    });
+    Ok(table)
+}
+
+/// Looks up the TAI-UTC offset, in seconds, at the given Julian date using
+/// the cached [`LeapSecondTable`].
+///
+/// # Errors
+///
+/// Returns an error if the leap-second table cannot be loaded.
+pub fn tai_minus_utc(jd: f64) -> Result<f64, Box<dyn Error>> {
+    Ok(leap_second_table()?.tai_minus_utc(jd))
+}
+
+/// Callback invoked roughly once per second with download progress; return
+/// `false` to cancel the transfer.
+pub type ProgressCallback = Box<dyn FnMut(&DownloadProgressRecord) -> bool>;
+
+/// Callback invoked exactly once with the final path of a fully downloaded
+/// (and, where applicable, integrity-checked) file.
+pub type FileNameHook = Box<dyn FnOnce(&Path)>;
+
+/// A progress snapshot for a download in progress, reported roughly once
+/// per second by the callback passed to [`get_bsp_file`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DownloadProgressRecord {
+    /// Time elapsed since this download attempt started.
+    pub elapsed: Duration,
+    /// Bytes written so far, including any resumed from a previous attempt.
+    pub current_bytes: u64,
+    /// Total size of the file, if the server reported a `Content-Length`.
+    pub total_bytes: Option<u64>,
+    /// Bytes per second since the previous notification.
+    pub last_throughput: f64,
+    /// Bytes per second averaged over the whole attempt.
+    pub total_throughput: f64,
+    /// `current_bytes / total_bytes * 100.0`, if `total_bytes` is known.
+    pub percentage_done: Option<f64>,
+}
+
+/// An expected checksum or size used to verify a downloaded file's integrity.
+#[derive(Debug, Clone)]
+pub enum Integrity {
+    /// A lowercase (or any-case) hex-encoded SHA-256 digest of the whole file.
+    Sha256(String),
+    /// The exact expected file size, in bytes.
+    Size(u64),
+}
+
+impl Integrity {
+    /// Verifies that the file at `path` matches this expectation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IntegrityError`] (distinct from ordinary I/O or HTTP
+    /// errors) if the file's size or digest doesn't match.
+    pub fn verify(&self, path: &str) -> Result<(), Box<dyn Error>> {
+        match self {
+            Integrity::Size(expected) => {
+                let actual = std::fs::metadata(path)?.len();
+                if actual != *expected {
+                    return Err(Box::new(IntegrityError {
+                        path: path.to_string(),
+                        expected: expected.to_string(),
+                        actual: actual.to_string(),
+                    }));
+                }
+            }
+            Integrity::Sha256(expected) => {
+                let bytes = std::fs::read(path)?;
+                let mut hasher = Sha256::new();
+                hasher.update(&bytes);
+                let actual = format!("{:x}", hasher.finalize());
+                if !actual.eq_ignore_ascii_case(expected) {
+                    return Err(Box::new(IntegrityError {
+                        path: path.to_string(),
+                        expected: expected.clone(),
+                        actual,
+                    }));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Raised by [`Integrity::verify`] when a downloaded file's checksum or size
+/// doesn't match what was expected, as distinct from ordinary I/O or HTTP
+/// download failures.
+#[derive(Debug)]
+pub struct IntegrityError {
+    pub path: String,
+    pub expected: String,
+    pub actual: String,
+}
+
+impl std::fmt::Display for IntegrityError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "integrity check failed for {}: expected {}, got {}",
+            self.path, self.expected, self.actual
+        )
+    }
+}
+
+impl Error for IntegrityError {}
+
 /// Downloads a BSP (Binary Space Partitioning) file if it does not exist or if an update is requested.
 ///
 /// # Arguments
@@ -85,103 +614,160 @@ pub fn get_tai_utc_data(update_file: bool) -> Result<(), Box<dyn Error>> {
 /// * `file_name` - The name of the BSP file to download.
 /// * `update_file` - If `true`, force an update even if the file already exists.
 /// * `minutes` - The maximum number of minutes to allow for the download operation.
+/// * `on_progress` - An optional callback invoked roughly once per second
+///   with a [`DownloadProgressRecord`]; return `false` to cancel the
+///   transfer.
+/// * `expected` - An optional [`Integrity`] check; a cached file that fails
+///   it is deleted and re-downloaded, and a freshly downloaded file that
+///   fails it is deleted so the next call re-fetches it.
+/// * `file_name_hook` - Optional callback fired exactly once, with the
+///   file's final path, after it is confirmed present and (if `expected`
+///   was given) has passed its integrity check.
+/// * `attempts` - How many download attempts to make in total before giving
+///   up; defaults to [`DEFAULT_DOWNLOAD_ATTEMPTS`] if `None`.
 ///
-/// # Panics
-///
-/// This function may panic if it encounters any errors during the download process.
+/// # Errors
 ///
-pub fn get_bsp_file(file_name: &str, update_file: bool, minutes: f64) -> Result<(), String> {
+/// Returns `Err` with a message if the download fails, is cancelled, or the
+/// resulting file fails its integrity check.
+pub fn get_bsp_file(
+    file_name: &str,
+    update_file: bool,
+    minutes: f64,
+    on_progress: Option<ProgressCallback>,
+    expected: Option<Integrity>,
+    file_name_hook: Option<FileNameHook>,
+    attempts: Option<u32>,
+) -> Result<(), String> {
     // Take in the file name and check whether it exists and if the user wants to update the file (if it already exists)
     let file_path = format!("data/{}", file_name);
 
-    // If file exists or update file is false, print to the terminal
     if !update_file && file_exists(&file_path) {
-        println!("File {} already exists. Skipping download.", file_name);
-        Ok(())
-    } else {
-        println!("Downloading {}", file_name);
-        // Clone the filename and wrap it in an Arc for sharing between threads
-        let file_name_clone = Arc::new(file_name.to_string());
-
-        // Start a timer thread
-        let timer_thread = thread::spawn({
-            let file_name_clone = Arc::clone(&file_name_clone);
-
-            move || {
-                let start_time = Instant::now();
-                // Set the time limit to 5 minutes for large files
-                let time_limit = Duration::from_secs((minutes * 60.0) as u64);
-
-                loop {
-                    let elapsed = start_time.elapsed();
-                    print!(
-                        // Clear the rest of the line
-                        "\rTime elapsed: {:.1} seconds                  ",
-                        elapsed.as_secs_f64()
-                    );
-                    // Flush stdout to make the output visible immediately
-                    std::io::stdout().flush().unwrap();
-
-                    // Check if the time limit has been reached
-                    if elapsed >= time_limit {
-                        println!(
-                            "\nTime limit of {:?} seconds ({:?} minutes) reached. Failed to download file. Exiting timer thread.",
-                            time_limit,
-                            time_limit / 60
-                        );
-                        // Exit the loop when the time limit is reached
-                        break;
-                    }
-
-                    // Check if the file has been downloaded
-                    if file_exists(&format!("data/{}", &file_name_clone)) == true {
-                        println!(
-                            "\nTime taken {:?}. File {} downloaded and saved successfully.",
-                            elapsed, &file_name_clone,
-                        );
-                        // Exit the loop when the time limit is reached
-                        break;
-                    }
-
-                    // Sleep for a short duration (e.g., 1 second)
-                    thread::sleep(Duration::from_secs(1));
+        match &expected {
+            Some(integrity) if integrity.verify(&file_path).is_err() => {
+                println!(
+                    "Cached file {} failed its integrity check. Re-downloading.",
+                    file_name
+                );
+                let _ = std::fs::remove_file(&file_path);
+            }
+            _ => {
+                println!("File {} already exists. Skipping download.", file_name);
+                if let Some(hook) = file_name_hook {
+                    hook(Path::new(&file_path));
                 }
+                return Ok(());
             }
-        });
+        }
+    }
 
-        // Simulate another operation in the main thread (e.g., a function)
-        match download_bsp_file(&file_name_clone, &file_path, minutes) {
-            Ok(_) => {}
-            Err(err) => {
-                println!("");
-                return Err(format!("Error downloading .bsp file: {}", err));
-            }
+    println!("Downloading {}", file_name);
+
+    let mut noop_progress = |_record: &DownloadProgressRecord| true;
+    let mut boxed_progress;
+    let callback: &mut dyn FnMut(&DownloadProgressRecord) -> bool = match on_progress {
+        Some(cb) => {
+            boxed_progress = cb;
+            &mut *boxed_progress
         }
-        thread::sleep(Duration::from_secs(2));
+        None => &mut noop_progress,
+    };
 
-        // Wait for the timer thread to finish
-        timer_thread.join().unwrap();
+    // The completion hook is deferred until after the integrity check below,
+    // rather than handed to `download_bsp_file` directly, so it only ever
+    // fires on a verified complete file.
+    download_bsp_file(
+        file_name,
+        &file_path,
+        minutes,
+        callback,
+        None,
+        attempts.unwrap_or(DEFAULT_DOWNLOAD_ATTEMPTS),
+    )
+    .map_err(|err| format!("Error downloading .bsp file: {}", err))?;
 
-        Ok(())
+    if let Some(integrity) = &expected {
+        if let Err(err) = integrity.verify(&file_path) {
+            let _ = std::fs::remove_file(&file_path);
+            return Err(format!("Error downloading .bsp file: {}", err));
+        }
+    }
+
+    if let Some(hook) = file_name_hook {
+        hook(Path::new(&file_path));
     }
+
+    Ok(())
 }
 
-/// Downloads a BSP (Binary Space Partitioning) file if it does not exist or if an update is requested.
+/// Default number of attempts [`download_bsp_file`] makes before giving up,
+/// retrying through transient network errors so a flaky connection
+/// eventually completes a large ephemeris download.
+pub const DEFAULT_DOWNLOAD_ATTEMPTS: u32 = 5;
+
+/// How often, at minimum, [`download_bsp_file`] notifies its progress
+/// callback while streaming a response body.
+const PROGRESS_NOTIFY_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Size of each chunk read from the response body while streaming.
+const DOWNLOAD_CHUNK_BYTES: usize = 64 * 1024;
+
+/// Downloads a BSP (Binary Space Partitioning) file, resuming from a
+/// previous partial download if one is staged.
+///
+/// The download streams to `<file_path>.partial` via HTTP `Range` requests,
+/// so an interrupted transfer resumes instead of restarting; the partial
+/// file is only renamed to `file_path` once the transfer completes. Makes
+/// up to `attempts` attempts in total, retrying through transient network
+/// errors. `on_progress` is
+/// called roughly once per second with a [`DownloadProgressRecord`];
+/// returning `false` aborts the transfer immediately, without retrying (the
+/// partial file is left in place so a later call can resume it).
 ///
 /// # Arguments
 ///
 /// * `file` - The name of the BSP file to download.
 /// * `file_path` - The local file path where the BSP file will be saved.
 /// * `minutes` - The maximum number of minutes to allow for the download operation.
+/// * `on_progress` - Callback invoked with streaming progress; return `false` to cancel.
+/// * `file_name_hook` - Optional callback fired exactly once, after the file
+///   has been fully written and renamed into place, with its final path.
+///   Lets downstream code parse the DAF segments or register the kernel
+///   immediately instead of polling the filesystem afterward.
+/// * `attempts` - How many attempts to make in total before giving up;
+///   see [`DEFAULT_DOWNLOAD_ATTEMPTS`] for the default most callers should use.
 ///
 /// # Errors
 ///
 /// This function returns a `Result<(), Box<dyn Error>>`:
 ///
-/// - If the file already exists but `update_file` is `true`, and the update fails.
-/// - If any other error occurs during the HTTP request, downloading, or file saving.
-// ///
-pub fn download_bsp_file(file: &str, file_path: &str, minutes: f64) -> Result<(), Box<dyn Error>> {
+/// - If every attempt fails with an HTTP or I/O error, or the transfer is cancelled.
+pub fn download_bsp_file(
+    file: &str,
+    file_path: &str,
+    minutes: f64,
+    on_progress: &mut dyn FnMut(&DownloadProgressRecord) -> bool,
+    file_name_hook: Option<FileNameHook>,
+    attempts: u32,
+) -> Result<(), Box<dyn Error>> {
+    download_bsp_file_with_attempts(
+        file,
+        file_path,
+        minutes,
+        attempts,
+        on_progress,
+        file_name_hook,
+    )
+}
+
+fn download_bsp_file_with_attempts(
+    file: &str,
+    file_path: &str,
+    minutes: f64,
+    attempts: u32,
+    on_progress: &mut dyn FnMut(&DownloadProgressRecord) -> bool,
+    file_name_hook: Option<FileNameHook>,
+) -> Result<(), Box<dyn Error>> {
     // Locate the URL for obtaining .bsp files
     let url = format!("https://ssd.jpl.nasa.gov/ftp/eph/planets/bsp/{}", file);
 
@@ -190,22 +776,171 @@ pub fn download_bsp_file(file: &str, file_path: &str, minutes: f64) -> Result<()
         .timeout(Duration::from_secs((minutes * 60.0) as u64))
         .build()?;
 
-    // Get the response
-    let resp = client.get(&url).send()?;
+    let partial_path = format!("{}.partial", file_path);
+    let mut last_err: Option<Box<dyn Error>> = None;
+
+    for attempt in 1..=attempts {
+        match download_bsp_attempt(&client, &url, &partial_path, &mut *on_progress) {
+            Ok(()) => {
+                std::fs::rename(&partial_path, file_path)?;
+                if let Some(hook) = file_name_hook {
+                    hook(Path::new(file_path));
+                }
+                return Ok(());
+            }
+            // A cancellation is a deliberate stop, not a transient failure
+            // worth retrying, so it breaks out of the attempt loop directly
+            // instead of falling through to `last_err`.
+            Err(err) if err.downcast_ref::<DownloadCancelled>().is_some() => {
+                return Err(err);
+            }
+            Err(err) => {
+                println!(
+                    "Attempt {}/{} to download {} failed: {}",
+                    attempt, attempts, file, err
+                );
+                last_err = Some(err);
+            }
+        }
+    }
+
+    Err(last_err.unwrap_or_else(|| format!("failed to download {}", file).into()))
+}
+
+/// Raised when `on_progress` returns `false` during a [`download_bsp_file`]
+/// transfer. Distinct from ordinary I/O or HTTP errors so the retry loop in
+/// [`download_bsp_file_with_attempts`] treats a deliberate cancellation as
+/// final instead of retrying it like a transient failure.
+#[derive(Debug)]
+pub struct DownloadCancelled;
+
+impl std::fmt::Display for DownloadCancelled {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "download cancelled by progress callback")
+    }
+}
+
+impl Error for DownloadCancelled {}
+
+/// Performs a single resumable download attempt into `partial_path`,
+/// sending a `Range: bytes=<existing_len>-` header for any bytes already
+/// staged there from a previous attempt, and streaming the response body
+/// in [`DOWNLOAD_CHUNK_BYTES`]-sized chunks so progress can be reported
+/// from real bytes transferred rather than polling the filesystem.
+///
+/// If the server responds `200 OK` instead of `206 Partial Content` (the
+/// range was not honored), the partial file is truncated and the download
+/// starts over. If it responds `416 Range Not Satisfiable` (the partial
+/// file's existing bytes are already at or past the end of the remote
+/// file), the partial file is likewise truncated and re-fetched from
+/// scratch, rather than resending the same unsatisfiable `Range` forever.
+fn download_bsp_attempt(
+    client: &reqwest::blocking::Client,
+    url: &str,
+    partial_path: &str,
+    on_progress: &mut dyn FnMut(&DownloadProgressRecord) -> bool,
+) -> Result<(), Box<dyn Error>> {
+    let mut existing_len = std::fs::metadata(partial_path).map(|m| m.len()).unwrap_or(0);
+
+    let mut request = client.get(url);
+    if existing_len > 0 {
+        request = request.header("Range", format!("bytes={}-", existing_len));
+    }
+
+    let mut resp = request.send()?;
+
+    if resp.status() == reqwest::StatusCode::RANGE_NOT_SATISFIABLE {
+        // The server has nothing left past `existing_len`. This is exactly
+        // the state a prior run leaves behind if it was killed after the
+        // last byte was written to `partial_path` but before the rename to
+        // `file_path`: retrying the same `Range` header would 416 forever.
+        // Truncate the partial file and re-fetch from scratch instead.
+        File::create(partial_path)?;
+        existing_len = 0;
+        resp = client.get(url).send()?;
+    }
 
     if !resp.status().is_success() {
-        return Err(format!("Failed to download {}: {:?}", file, resp.status()).into());
+        return Err(format!("Failed to download: {:?}", resp.status()).into());
     }
 
-    let body = resp.bytes()?;
+    let resumed = existing_len > 0 && resp.status() == reqwest::StatusCode::PARTIAL_CONTENT;
+    let mut out = if resumed {
+        std::fs::OpenOptions::new().append(true).open(partial_path)?
+    } else {
+        File::create(partial_path)?
+    };
+
+    let total_bytes = match resp.content_length() {
+        Some(remaining) if resumed => Some(existing_len + remaining),
+        Some(total) => Some(total),
+        None => None,
+    };
+
+    let start = Instant::now();
+    let mut current_bytes = if resumed { existing_len } else { 0 };
+    let start_bytes = current_bytes;
+    let mut last_notify_at = start;
+    let mut bytes_since_last_notify = 0u64;
+
+    let mut buf = [0u8; DOWNLOAD_CHUNK_BYTES];
+    loop {
+        let read = resp.read(&mut buf)?;
+        if read == 0 {
+            break;
+        }
+
+        out.write_all(&buf[..read])?;
+        current_bytes += read as u64;
+        bytes_since_last_notify += read as u64;
+
+        let now = Instant::now();
+        let since_last_notify = now.duration_since(last_notify_at);
+        if since_last_notify < PROGRESS_NOTIFY_INTERVAL {
+            continue;
+        }
+
+        let elapsed = now.duration_since(start);
+        let record = DownloadProgressRecord {
+            elapsed,
+            current_bytes,
+            total_bytes,
+            last_throughput: bytes_since_last_notify as f64 / since_last_notify.as_secs_f64(),
+            total_throughput: (current_bytes - start_bytes) as f64 / elapsed.as_secs_f64(),
+            percentage_done: total_bytes
+                .map(|total| current_bytes as f64 / total as f64 * 100.0),
+        };
 
-    let mut out = File::create(file_path)?;
+        if !on_progress(&record) {
+            return Err(Box::new(DownloadCancelled));
+        }
+
+        last_notify_at = now;
+        bytes_since_last_notify = 0;
+    }
 
-    out.write_all(&body)?;
+    let elapsed = Instant::now().duration_since(start);
+    let final_record = DownloadProgressRecord {
+        elapsed,
+        current_bytes,
+        total_bytes,
+        last_throughput: bytes_since_last_notify as f64
+            / now_duration_since_or_epsilon(last_notify_at),
+        total_throughput: (current_bytes - start_bytes) as f64
+            / elapsed.as_secs_f64().max(f64::EPSILON),
+        percentage_done: total_bytes.map(|total| current_bytes as f64 / total as f64 * 100.0),
+    };
+    on_progress(&final_record);
 
     Ok(())
 }
 
+/// Seconds elapsed since `since`, floored to a tiny epsilon so a final
+/// progress notification immediately after a prior one doesn't divide by zero.
+fn now_duration_since_or_epsilon(since: Instant) -> f64 {
+    Instant::now().duration_since(since).as_secs_f64().max(f64::EPSILON)
+}
+
 /// Converts a snake_case or kebab-case string to a readable, space-separated string in Title Case.
 ///
 /// This function takes an input string in snake_case or kebab-case and converts it to a readable
@@ -262,3 +997,211 @@ pub fn is_binary(file: &File) -> bool {
         true
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_tai_utc_line_valid() {
+        let line = " 1972 JAN  1 =JD 2441317.5  TAI-UTC=  10.0       S + (MJD - 41317.) X 0.0      S";
+        let row = parse_tai_utc_line(line).expect("line should parse");
+        assert_eq!(row.effective_jd, 2441317.5);
+        assert_eq!(row.base_offset, 10.0);
+        assert_eq!(row.mjd_epoch, 41317.0);
+        assert_eq!(row.drift_per_day, 0.0);
+    }
+
+    #[test]
+    fn test_parse_tai_utc_line_rejects_non_matching_lines() {
+        assert!(parse_tai_utc_line("not a data line").is_none());
+    }
+
+    #[test]
+    fn test_parse_leap_seconds_list_line_valid() {
+        let row = parse_leap_seconds_list_line("2272060800\t10\t# 1 Jan 1972").unwrap();
+        assert_eq!(row.base_offset, 10.0);
+        assert_eq!(row.mjd_epoch, 0.0);
+        assert_eq!(row.drift_per_day, 0.0);
+    }
+
+    #[test]
+    fn test_parse_leap_seconds_list_line_ignores_comments_and_blanks() {
+        assert!(parse_leap_seconds_list_line("# a comment").is_none());
+        assert!(parse_leap_seconds_list_line("   ").is_none());
+    }
+
+    #[test]
+    fn test_ntp_seconds_to_julian_days() {
+        // The NTP epoch, 1900-01-01, is Julian date 2415020.5.
+        assert!((ntp_seconds_to_julian_days(0) - 2415020.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_leap_second_table_tai_minus_utc_uses_latest_applicable_row() {
+        let table = LeapSecondTable {
+            rows: vec![
+                LeapSecondRow {
+                    effective_jd: 2441317.5,
+                    base_offset: 10.0,
+                    mjd_epoch: 41317.0,
+                    drift_per_day: 0.0,
+                },
+                LeapSecondRow {
+                    effective_jd: 2441499.5,
+                    base_offset: 11.0,
+                    mjd_epoch: 41499.0,
+                    drift_per_day: 0.0,
+                },
+            ],
+        };
+        assert_eq!(table.tai_minus_utc(2441317.5), 10.0);
+        assert_eq!(table.tai_minus_utc(2441600.0), 11.0);
+    }
+
+    #[test]
+    fn test_leap_second_table_tai_minus_utc_before_first_row_uses_fallback() {
+        let table = LeapSecondTable {
+            rows: vec![LeapSecondRow {
+                effective_jd: 2441317.5,
+                base_offset: 10.0,
+                mjd_epoch: 41317.0,
+                drift_per_day: 0.0,
+            }],
+        };
+        assert_eq!(table.tai_minus_utc(0.0), 10.0);
+    }
+
+    #[test]
+    fn test_parse_usno_sorts_rows_with_nan_jd_without_panicking() {
+        let contents = "\
+ 1972 JAN  1 =JD 2441499.5  TAI-UTC=  11.0       S + (MJD - 41499.) X 0.0      S
+ 1972 JAN  1 =JD nan  TAI-UTC=  99.0       S + (MJD - 0.) X 0.0      S
+ 1972 JAN  1 =JD 2441317.5  TAI-UTC=  10.0       S + (MJD - 41317.) X 0.0      S";
+        let table = LeapSecondTable::parse_usno(contents, "test").expect("should parse");
+        assert_eq!(table.rows.len(), 3);
+    }
+
+    #[test]
+    fn test_is_stale_missing_file_is_stale() {
+        assert!(is_stale(
+            "data/does-not-exist-for-tests.dat",
+            Duration::from_secs(60)
+        ));
+    }
+
+    #[test]
+    fn test_is_stale_fresh_tai_utc_file_is_not_stale() {
+        let path = std::env::temp_dir().join("astrorust-test-is-stale-tai-utc.dat");
+        std::fs::write(&path, "placeholder").unwrap();
+        assert!(!is_stale(path.to_str().unwrap(), Duration::from_secs(3600)));
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn test_integrity_size_mismatch_is_an_error() {
+        let path = std::env::temp_dir().join("astrorust-test-integrity-size.bin");
+        std::fs::write(&path, b"hello").unwrap();
+        assert!(Integrity::Size(999).verify(path.to_str().unwrap()).is_err());
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn test_integrity_sha256_matches_expected_digest() {
+        let path = std::env::temp_dir().join("astrorust-test-integrity-sha256.bin");
+        std::fs::write(&path, b"hello").unwrap();
+
+        let mut hasher = Sha256::new();
+        hasher.update(b"hello");
+        let digest = format!("{:x}", hasher.finalize());
+
+        assert!(Integrity::Sha256(digest)
+            .verify(path.to_str().unwrap())
+            .is_ok());
+        std::fs::remove_file(&path).ok();
+    }
+
+    /// Serializes tests that manipulate the hardcoded `data/tai-utc.dat` /
+    /// `data/leap-seconds.list` cache paths read by [`get_tai_utc_data`],
+    /// since `cargo test` runs tests in parallel by default.
+    static DATA_DIR_TEST_LOCK: Mutex<()> = Mutex::new(());
+
+    /// Removes any cached leap-second files left over from a previous run.
+    fn clear_cached_leap_second_files() {
+        std::fs::create_dir_all("data").unwrap();
+        std::fs::remove_file("data/tai-utc.dat").ok();
+        std::fs::remove_file("data/leap-seconds.list").ok();
+    }
+
+    #[test]
+    fn test_get_tai_utc_data_fresh_cache_skips_every_source() {
+        let _guard = DATA_DIR_TEST_LOCK.lock().unwrap();
+        clear_cached_leap_second_files();
+        std::fs::write("data/tai-utc.dat", "placeholder").unwrap();
+
+        // No sources are given, so if this had to refresh it would fail;
+        // a fresh cache should mean it never tries.
+        let freshness = get_tai_utc_data(&[], false, Duration::from_secs(3600)).unwrap();
+        assert_eq!(freshness, DataFreshness::Fresh);
+
+        clear_cached_leap_second_files();
+    }
+
+    #[test]
+    fn test_get_tai_utc_data_falls_back_through_every_failing_source_to_stale_cache() {
+        let _guard = DATA_DIR_TEST_LOCK.lock().unwrap();
+        clear_cached_leap_second_files();
+        // A cache old enough (max_age 0) to need a refresh.
+        std::fs::write("data/tai-utc.dat", "placeholder").unwrap();
+
+        let sources = ["not-a-valid-url", "also-not-a-valid-url"];
+        let freshness = get_tai_utc_data(&sources, false, Duration::from_secs(0)).unwrap();
+        assert_eq!(freshness, DataFreshness::StaleFallback);
+
+        clear_cached_leap_second_files();
+    }
+
+    #[test]
+    fn test_get_tai_utc_data_errors_when_no_cache_and_every_source_fails() {
+        let _guard = DATA_DIR_TEST_LOCK.lock().unwrap();
+        clear_cached_leap_second_files();
+
+        let sources = ["not-a-valid-url"];
+        assert!(get_tai_utc_data(&sources, false, Duration::from_secs(3600)).is_err());
+
+        clear_cached_leap_second_files();
+    }
+
+    #[test]
+    fn test_get_tai_utc_data_refreshes_from_a_working_source() {
+        use std::net::TcpListener;
+
+        let _guard = DATA_DIR_TEST_LOCK.lock().unwrap();
+        clear_cached_leap_second_files();
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let body =
+            " 1972 JAN  1 =JD 2441317.5  TAI-UTC=  10.0       S + (MJD - 41317.) X 0.0      S\n";
+
+        let server = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut buf = [0u8; 1024];
+            let _ = stream.read(&mut buf);
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            stream.write_all(response.as_bytes()).unwrap();
+        });
+
+        let url = format!("http://{}/tai-utc.dat", addr);
+        let freshness = get_tai_utc_data(&[&url], false, Duration::from_secs(3600)).unwrap();
+        assert_eq!(freshness, DataFreshness::Refreshed);
+        assert!(file_exists("data/tai-utc.dat"));
+
+        server.join().unwrap();
+        clear_cached_leap_second_files();
+    }
+}
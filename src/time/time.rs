@@ -1,4 +1,7 @@
-use crate::utils::constants::DAY_S;
+use std::error::Error;
+
+use crate::data_utils::data_utils::tai_minus_utc;
+use crate::utils::constants::{DAY_S, TAI_TT_DIFF};
 
 #[derive(Debug, Clone)]
 pub struct Time {
@@ -32,6 +35,66 @@ impl Time {
         (self.whole_days, self.whole_seconds, self.fractional_seconds) =
             convert_jd_to_days_and_seconds(jd);
     }
+
+    /// Returns the Julian date represented by this `Time`.
+    pub fn to_julian_days(&self) -> f64 {
+        self.whole_days as f64 + (self.whole_seconds as f64 + self.fractional_seconds) / DAY_S
+    }
+
+    /// Converts this `Time`, interpreted as UTC, to TAI using the cached
+    /// leap-second table (parsed from `data/tai-utc.dat` or
+    /// `data/leap-seconds.list`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the leap-second table cannot be loaded.
+    pub fn utc_to_tai(&self) -> Result<Self, Box<dyn Error>> {
+        let jd = self.to_julian_days();
+        let offset = tai_minus_utc(jd)?;
+
+        let mut tai = self.clone();
+        tai.set_julian_days(jd + offset / DAY_S);
+        Ok(tai)
+    }
+
+    /// Converts this `Time`, interpreted as TAI, back to UTC.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the leap-second table cannot be loaded.
+    pub fn tai_to_utc(&self) -> Result<Self, Box<dyn Error>> {
+        let jd = self.to_julian_days();
+        // The leap-second table is indexed by UTC julian date, but we only
+        // have the TAI julian date here. A lookup at the TAI date gives an
+        // estimate of the UTC date; a second lookup at that estimate gives
+        // the offset in effect there, which stays correct in the few seconds
+        // after a boundary where the TAI date is already past it.
+        let estimate = tai_minus_utc(jd)?;
+        let offset = tai_minus_utc(jd - estimate / DAY_S)?;
+
+        let mut utc = self.clone();
+        utc.set_julian_days(jd - offset / DAY_S);
+        Ok(utc)
+    }
+
+    /// Converts this `Time`, interpreted as TAI, to TT using the fixed
+    /// `TAI_TT_DIFF` offset.
+    pub fn tai_to_tt(&self) -> Self {
+        let jd = self.to_julian_days();
+
+        let mut tt = self.clone();
+        tt.set_julian_days(jd + TAI_TT_DIFF);
+        tt
+    }
+
+    /// Converts this `Time`, interpreted as TT, back to TAI.
+    pub fn tt_to_tai(&self) -> Self {
+        let jd = self.to_julian_days();
+
+        let mut tai = self.clone();
+        tai.set_julian_days(jd - TAI_TT_DIFF);
+        tai
+    }
 }
 
 pub fn convert_jd_to_days_and_seconds(jd: f64) -> (u64, u64, f64) {
@@ -59,4 +122,16 @@ mod tests {
         assert_eq!(actual_seconds, expected_seconds);
         assert_eq!(actual_fractional_seconds, expected_fractional_seconds);
     }
+
+    #[test]
+    fn test_tai_tt_round_trip() {
+        let mut tai = Time::new(0, 0, 0.0);
+        tai.set_julian_days(2451545.0);
+
+        let tt = tai.tai_to_tt();
+        assert_eq!(tt.to_julian_days(), 2451545.0 + TAI_TT_DIFF);
+
+        let round_tripped = tt.tt_to_tai();
+        assert!((round_tripped.to_julian_days() - tai.to_julian_days()).abs() < 1e-9);
+    }
 }